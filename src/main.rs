@@ -1,11 +1,24 @@
-use std::num::NonZeroU64;
+use std::{collections::HashMap, num::NonZeroU64, path::PathBuf, sync::Arc, time::Duration};
 
 use aws_config::BehaviorVersion;
 use aws_sdk_secretsmanager::{
-    operation::{create_secret::CreateSecretError, delete_secret::DeleteSecretError},
+    operation::{
+        create_secret::CreateSecretError, delete_secret::DeleteSecretError,
+        list_secrets::ListSecretsError, put_secret_value::PutSecretValueError,
+    },
+    types::{SecretListEntry, Tag},
+    Client,
 };
+use aws_smithy_types::error::metadata::ProvideErrorMetadata;
 use clap::{Parser, Subcommand};
+use futures::{
+    pin_mut,
+    stream::{self, Stream, StreamExt},
+};
 use indicatif::{MultiProgress, ProgressBar, ProgressDrawTarget, ProgressStyle};
+use rand::Rng;
+use serde::Deserialize;
+use tokio::sync::{mpsc, Mutex};
 
 #[derive(Parser)]
 #[command(name = "secret-populator")]
@@ -13,6 +26,18 @@ use indicatif::{MultiProgress, ProgressBar, ProgressDrawTarget, ProgressStyle};
 struct Args {
     #[arg(long)]
     endpoint_url: Option<String>,
+    /// Maximum number of create/delete requests in flight at once.
+    #[arg(short = 'j', long, default_value = "10")]
+    concurrency: NonZeroU64,
+    /// Minimum milliseconds between starting one secret and the next, enforced globally
+    /// across all workers, to cap the rate against Secrets Manager without having to
+    /// lower `--concurrency` to 1. Retries and the follow-up calls made for a secret
+    /// that has already started (tagging, upsert updates) are not spaced out.
+    #[arg(long)]
+    tranquility: Option<u64>,
+    /// How many times to retry a request after a throttling or other transient service error.
+    #[arg(long, default_value = "5")]
+    max_retries: u32,
     #[command(subcommand)]
     command: Command,
 }
@@ -30,21 +55,371 @@ enum Command {
         count: NonZeroU64,
         #[arg(short, long, default_value = "generated-secret")]
         prefix: String,
+        /// Delete every secret matching `--prefix`, discovered via ListSecrets, instead of the numeric `--count` range.
+        #[arg(long)]
+        all: bool,
+    },
+    /// Stream every secret whose name starts with `--prefix` to stdout.
+    List {
+        #[arg(short, long, default_value = "generated-secret")]
+        prefix: String,
+    },
+    /// Create secrets from a JSON or YAML manifest instead of synthetic values.
+    Load {
+        /// Path to a manifest mapping secret names to values (`.json`/`.yaml`/`.yml`).
+        #[arg(long)]
+        file: PathBuf,
+        /// If a secret already exists, update its value/description/tags instead of counting it as an error.
+        #[arg(long)]
+        upsert: bool,
+    },
+    /// Rotate the value of existing secrets, optionally also attaching or removing tags.
+    Update {
+        #[arg(short, long, default_value = "10")]
+        count: NonZeroU64,
+        #[arg(short, long, default_value = "generated-secret")]
+        prefix: String,
+        /// Rotate the secrets named in a manifest instead of `--prefix`/`--count`.
+        #[arg(long)]
+        file: Option<PathBuf>,
+        /// Tag to attach or overwrite, as `key=value` (repeatable). Not applied to
+        /// manifest entries that list tags of their own; those tags are used instead.
+        #[arg(long = "tag", value_parser = parse_tag)]
+        tags: Vec<(String, String)>,
+        /// Tag key to remove (repeatable).
+        #[arg(long = "untag")]
+        untag: Vec<String>,
     },
 }
 
+fn parse_tag(s: &str) -> Result<(String, String), String> {
+    s.split_once('=')
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .ok_or_else(|| format!("tag `{s}` must be in `key=value` form"))
+}
+
+/// One secret to rotate: its new value plus any tags to attach/overwrite or
+/// remove. Built either from `--prefix`/`--count` or from a manifest file.
+struct UpdateTarget {
+    name: String,
+    value: String,
+    tags_to_set: HashMap<String, String>,
+    tags_to_remove: Vec<String>,
+}
+
+/// One secret to populate, as read from a manifest file. The plain map form
+/// (`{ "name": "value" }`) is turned into these by `Manifest::into_entries`
+/// with no description or tags; the richer array form sets them explicitly.
+#[derive(Deserialize)]
+struct ManifestEntry {
+    name: String,
+    value: String,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    tags: HashMap<String, String>,
+}
+
+/// A manifest file is either a plain `{ name: value }` map or an array of
+/// `ManifestEntry` objects; `serde(untagged)` tries each in turn.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Manifest {
+    Map(HashMap<String, String>),
+    List(Vec<ManifestEntry>),
+}
+
+impl Manifest {
+    fn into_entries(self) -> Vec<ManifestEntry> {
+        match self {
+            Manifest::Map(map) => map
+                .into_iter()
+                .map(|(name, value)| ManifestEntry {
+                    name,
+                    value,
+                    description: None,
+                    tags: HashMap::new(),
+                })
+                .collect(),
+            Manifest::List(entries) => entries,
+        }
+    }
+}
+
+/// Reads and parses a manifest file, picking JSON or YAML based on its
+/// extension (defaulting to JSON for anything else).
+fn load_manifest(path: &std::path::Path) -> Result<Vec<ManifestEntry>, Box<dyn std::error::Error>> {
+    let contents = std::fs::read_to_string(path)?;
+    let manifest: Manifest = match path.extension().and_then(|ext| ext.to_str()) {
+        Some("yaml") | Some("yml") => serde_yaml::from_str(&contents)?,
+        _ => serde_json::from_str(&contents)?,
+    };
+    Ok(manifest.into_entries())
+}
+
+/// A `SecretListEntry` reduced to the fields the CLI actually prints.
+struct SecretSummary {
+    name: String,
+    arn: String,
+    last_changed: Option<aws_smithy_types::DateTime>,
+}
+
+impl From<SecretListEntry> for SecretSummary {
+    fn from(entry: SecretListEntry) -> Self {
+        Self {
+            name: entry.name.unwrap_or_default(),
+            arn: entry.arn.unwrap_or_default(),
+            last_changed: entry.last_changed_date,
+        }
+    }
+}
+
+/// Streams every secret whose name starts with `prefix`, paginating through
+/// `ListSecrets` under the hood so callers never see a truncated result.
+/// Shared by the `List` subcommand and `Delete --all`.
+fn list_secrets_by_prefix<'a>(
+    client: &'a Client,
+    prefix: &'a str,
+) -> impl Stream<Item = Result<SecretSummary, aws_sdk_secretsmanager::error::SdkError<ListSecretsError>>>
+       + 'a {
+    client
+        .list_secrets()
+        .into_paginator()
+        .items()
+        .send()
+        .map(|entry| entry.map(SecretSummary::from))
+        .filter(move |result| {
+            // Always keep `Err`s so a failed page propagates via `?` instead
+            // of being silently dropped alongside non-matching names.
+            let keep = match result {
+                Ok(s) => s.name.starts_with(prefix),
+                Err(_) => true,
+            };
+            async move { keep }
+        })
+}
+
+/// Outcome of a single create/delete call, distinguishing a successful
+/// operation from one that was skipped because the secret already existed
+/// (create) or was already gone (delete). Both count toward the returned
+/// error total, but neither aborts the run.
+enum Outcome {
+    Done,
+    Skipped,
+}
+
+/// A fixed-size pool of worker slots, each with its own spinner bar, so a
+/// bounded-concurrency run can show what every in-flight request is doing.
+struct WorkerPool {
+    spinners: Vec<ProgressBar>,
+    free_tx: mpsc::UnboundedSender<usize>,
+    free_rx: Mutex<mpsc::UnboundedReceiver<usize>>,
+}
+
+impl WorkerPool {
+    fn new(mp: &MultiProgress, concurrency: usize) -> Self {
+        let style = ProgressStyle::with_template("  {spinner:.green} worker {prefix:>2}: {msg}")
+            .unwrap();
+        let (free_tx, free_rx) = mpsc::unbounded_channel();
+        let spinners = (0..concurrency)
+            .map(|i| {
+                let spinner = mp.add(
+                    ProgressBar::new_spinner()
+                        .with_style(style.clone())
+                        .with_prefix(i.to_string()),
+                );
+                spinner.enable_steady_tick(Duration::from_millis(100));
+                spinner.set_message("idle");
+                free_tx.send(i).unwrap();
+                spinner
+            })
+            .collect();
+        Self {
+            spinners,
+            free_tx,
+            free_rx: Mutex::new(free_rx),
+        }
+    }
+
+    /// Waits for a free worker slot, returning its index and spinner bar.
+    /// The caller must give the slot back via `release` when it's done.
+    async fn acquire(&self) -> (usize, ProgressBar) {
+        let slot = self
+            .free_rx
+            .lock()
+            .await
+            .recv()
+            .await
+            .expect("pool outlives its workers");
+        (slot, self.spinners[slot].clone())
+    }
+
+    fn release(&self, slot: usize) {
+        self.spinners[slot].set_message("idle");
+        let _ = self.free_tx.send(slot);
+    }
+}
+
+/// A global pacing gate enforcing a minimum spacing between dispatches
+/// across every worker, so `--tranquility` is an actual request-rate cap
+/// rather than a delay each worker pays independently in parallel.
+struct RateLimiter {
+    interval: Duration,
+    next_slot: Mutex<tokio::time::Instant>,
+}
+
+impl RateLimiter {
+    fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            next_slot: Mutex::new(tokio::time::Instant::now()),
+        }
+    }
+
+    /// Blocks until the next slot this limiter will allow, then reserves it.
+    async fn acquire(&self) {
+        let slot = {
+            let mut next_slot = self.next_slot.lock().await;
+            let slot = std::cmp::max(tokio::time::Instant::now(), *next_slot);
+            *next_slot = slot + self.interval;
+            slot
+        };
+        tokio::time::sleep_until(slot).await;
+    }
+}
+
+/// Whether an `.send()` failure is worth retrying: timeouts, dispatch
+/// failures, unparseable responses, and throttling, request-limit and 5xx
+/// service errors are transient; everything else (including a plain
+/// `ResourceExistsException`/`ResourceNotFoundException`) is not.
+fn is_retryable<E: ProvideErrorMetadata>(err: &aws_sdk_secretsmanager::error::SdkError<E>) -> bool {
+    use aws_sdk_secretsmanager::error::SdkError;
+    match err {
+        SdkError::TimeoutError(_) | SdkError::DispatchFailure(_) | SdkError::ResponseError(_) => {
+            true
+        }
+        SdkError::ServiceError(ctx) => {
+            matches!(
+                ctx.err().code(),
+                Some("ThrottlingException") | Some("RequestLimitExceeded")
+            ) || ctx.raw().status().as_u16() >= 500
+        }
+        _ => false,
+    }
+}
+
+/// Converts a send failure into a boxed error without panicking, whether or
+/// not it carries a modeled service error — `into_service_error()` panics on
+/// `TimeoutError`/`DispatchFailure`/`ResponseError`, which is exactly what a
+/// run that exhausts its retries on a transient failure looks like.
+fn into_boxed_error<E: std::error::Error + Send + Sync + 'static>(
+    err: aws_sdk_secretsmanager::error::SdkError<E>,
+) -> Box<dyn std::error::Error> {
+    match err.try_into_service_error() {
+        Ok(svc) => svc.into(),
+        Err(other) => other.into(),
+    }
+}
+
+/// Retries `call` with capped exponential backoff plus full jitter on
+/// transient errors: `delay = min(cap, base * 2^attempt)`, then sleeps a
+/// random duration in `[0, delay]` before trying again. Gives up and
+/// propagates the error once `max_retries` retries have failed, i.e. after
+/// `max_retries + 1` attempts in all.
+async fn send_with_retry<T, E, F, Fut>(
+    max_retries: u32,
+    name: &str,
+    spinner: &ProgressBar,
+    mut call: F,
+) -> Result<T, aws_sdk_secretsmanager::error::SdkError<E>>
+where
+    E: ProvideErrorMetadata,
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = Result<T, aws_sdk_secretsmanager::error::SdkError<E>>>,
+{
+    const BASE: Duration = Duration::from_millis(200);
+    const CAP: Duration = Duration::from_secs(20);
+
+    let mut attempt = 0u32;
+    loop {
+        match call().await {
+            Ok(value) => return Ok(value),
+            Err(err) if attempt < max_retries && is_retryable(&err) => {
+                attempt += 1;
+                let factor = 2u32.saturating_pow(attempt) as u64;
+                let delay_ms = (BASE.as_millis() as u64).saturating_mul(factor);
+                let delay = std::cmp::min(CAP, Duration::from_millis(delay_ms));
+                let jitter_ms = rand::thread_rng().gen_range(0..=delay.as_millis() as u64);
+                spinner.println(format!("retrying {name} (attempt {attempt})"));
+                tokio::time::sleep(Duration::from_millis(jitter_ms)).await;
+            }
+            Err(err) => return Err(err),
+        }
+    }
+}
+
+/// Runs `op` over `items` with at most `concurrency` calls in flight at
+/// once, ticking `main_pb` once per completed item and routing each call
+/// through a free slot in `pool` so its spinner shows what it's working on.
+/// Returns the number of `Outcome::Skipped` results; the first hard error
+/// aborts the run and is propagated.
+async fn run_bulk<T, F, Fut>(
+    items: Vec<T>,
+    concurrency: usize,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    main_pb: ProgressBar,
+    pool: Arc<WorkerPool>,
+    op: F,
+) -> Result<u64, Box<dyn std::error::Error>>
+where
+    T: Send + 'static,
+    F: Fn(T, ProgressBar) -> Fut + Clone + Send + 'static,
+    Fut: std::future::Future<Output = Result<Outcome, Box<dyn std::error::Error>>> + Send,
+{
+    let mut stream = stream::iter(items.into_iter().map(|item| {
+        let pool = Arc::clone(&pool);
+        let rate_limiter = rate_limiter.clone();
+        let op = op.clone();
+        async move {
+            let (slot, spinner) = pool.acquire().await;
+            if let Some(limiter) = &rate_limiter {
+                limiter.acquire().await;
+            }
+            let result = op(item, spinner).await;
+            pool.release(slot);
+            result
+        }
+    }))
+    .buffer_unordered(concurrency);
+
+    let mut errors = 0u64;
+    while let Some(result) = stream.next().await {
+        main_pb.inc(1);
+        match result? {
+            Outcome::Done => {}
+            Outcome::Skipped => errors += 1,
+        }
+    }
+    Ok(errors)
+}
+
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let args = Args::parse();
     let config = aws_config::load_defaults(BehaviorVersion::latest()).await;
     let mut client_config = aws_sdk_secretsmanager::config::Builder::from(&config);
     client_config.set_endpoint_url(args.endpoint_url);
-    let client = aws_sdk_secretsmanager::Client::from_conf(client_config.build());
+    let client = Client::from_conf(client_config.build());
+
+    let concurrency = args.concurrency.get() as usize;
+    let rate_limiter = args
+        .tranquility
+        .map(|ms| Arc::new(RateLimiter::new(Duration::from_millis(ms))));
 
     match args.command {
         Command::Create { count, prefix } => {
-            let mp = MultiProgress::new();
-            let pb = mp.add(
+            let mp = MultiProgress::with_draw_target(ProgressDrawTarget::stderr_with_hz(10));
+            let main_pb = mp.add(
                 ProgressBar::new(count.get()).with_style(
                     ProgressStyle::with_template(
                         "[{elapsed_precise}] {bar:60.cyan/blue} {pos:>7}/{len:7} {msg}",
@@ -52,76 +427,393 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     .unwrap(),
                 ),
             );
+            let pool = Arc::new(WorkerPool::new(&mp, concurrency));
+
+            let items: Vec<(String, u64)> = (1..=count.get())
+                .map(|i| (format!("{}-{}", prefix, i), i))
+                .collect();
 
-            pb.set_draw_target(ProgressDrawTarget::stderr_with_hz(10));
-
-            let mut errors: u64 = 0;
-            for i in pb.wrap_iter(1..=count.get()) {
-                let name = format!("{}-{}", prefix, i);
-
-                match client
-                    .create_secret()
-                    .name(&name)
-                    .secret_string(format!("secret-value-{}", i))
-                    .send()
-                    .await
-                {
-                    Ok(_) => pb.set_message(format!("Created secret: {}", name)),
-                    Err(e) => match e.into_service_error() {
-                        CreateSecretError::ResourceExistsException(_) => {
-                            pb.println(format!("Secret already exists: {}", name));
-                            errors = errors + 1;
+            let client = client.clone();
+            let max_retries = args.max_retries;
+            let errors = run_bulk(
+                items,
+                concurrency,
+                rate_limiter.clone(),
+                main_pb.clone(),
+                pool,
+                move |(name, i), spinner| {
+                    let client = client.clone();
+                    async move {
+                        spinner.set_message(format!("creating {name}"));
+                        let result = send_with_retry(max_retries, &name, &spinner, || {
+                            client
+                                .create_secret()
+                                .name(&name)
+                                .secret_string(format!("secret-value-{}", i))
+                                .send()
+                        })
+                        .await;
+                        match result {
+                            Ok(_) => Ok(Outcome::Done),
+                            Err(e) => match e.try_into_service_error() {
+                                Ok(CreateSecretError::ResourceExistsException(_)) => {
+                                    spinner.println(format!("Secret already exists: {name}"));
+                                    Ok(Outcome::Skipped)
+                                }
+                                Ok(err) => Err(err.into()),
+                                Err(other) => Err(other.into()),
+                            },
                         }
-                        err => return Err(err.into()),
-                    },
+                    }
+                },
+            )
+            .await?;
+
+            main_pb.finish_with_message(format!(
+                "Created {} secrets ({errors} already existed)",
+                count.get() - errors
+            ));
+        }
+        Command::Delete {
+            count,
+            prefix,
+            all,
+        } => {
+            let names: Vec<String> = if all {
+                let stream = list_secrets_by_prefix(&client, &prefix);
+                pin_mut!(stream);
+                let mut names = Vec::new();
+                while let Some(result) = stream.next().await {
+                    names.push(result?.name);
                 }
-            }
+                names
+            } else {
+                (1..=count.get())
+                    .map(|i| format!("{}-{}", prefix, i))
+                    .collect()
+            };
+
+            let mp = MultiProgress::with_draw_target(ProgressDrawTarget::stderr_with_hz(10));
+            let main_pb = mp.add(
+                ProgressBar::new(names.len() as u64).with_style(
+                    ProgressStyle::with_template(
+                        "[{elapsed_precise}] {bar:60.cyan/blue} {pos:>7}/{len:7} {msg}",
+                    )
+                    .unwrap(),
+                ),
+            );
+            let pool = Arc::new(WorkerPool::new(&mp, concurrency));
 
-            if errors == 0 {
-                pb.set_message(format!("Created {count} secrets"));
+            let total = names.len() as u64;
+            let client = client.clone();
+            let max_retries = args.max_retries;
+            let errors = run_bulk(
+                names,
+                concurrency,
+                rate_limiter.clone(),
+                main_pb.clone(),
+                pool,
+                move |name, spinner| {
+                    let client = client.clone();
+                    async move {
+                        spinner.set_message(format!("deleting {name}"));
+                        let result = send_with_retry(max_retries, &name, &spinner, || {
+                            client
+                                .delete_secret()
+                                .secret_id(&name)
+                                .force_delete_without_recovery(true)
+                                .send()
+                        })
+                        .await;
+                        match result {
+                            Ok(_) => Ok(Outcome::Done),
+                            Err(e) => match e.try_into_service_error() {
+                                Ok(DeleteSecretError::ResourceNotFoundException(_)) => {
+                                    spinner.println(format!("Secret not found: {name}"));
+                                    Ok(Outcome::Skipped)
+                                }
+                                Ok(err) => Err(err.into()),
+                                Err(other) => Err(other.into()),
+                            },
+                        }
+                    }
+                },
+            )
+            .await?;
+
+            main_pb.finish_with_message(format!(
+                "Deleted {} secrets ({errors} not found)",
+                total - errors
+            ));
+        }
+        Command::List { prefix } => {
+            let stream = list_secrets_by_prefix(&client, &prefix);
+            pin_mut!(stream);
+            println!("{:<40}  {:<70}  LAST CHANGED", "NAME", "ARN");
+            while let Some(result) = stream.next().await {
+                let summary = result?;
+                let last_changed = summary
+                    .last_changed
+                    .map(|d| d.to_string())
+                    .unwrap_or_else(|| "-".to_string());
+                println!(
+                    "{:<40}  {:<70}  {last_changed}",
+                    summary.name, summary.arn
+                );
             }
+        }
+        Command::Load { file, upsert } => {
+            let entries = load_manifest(&file)?;
 
-            pb.finish();
+            let mp = MultiProgress::with_draw_target(ProgressDrawTarget::stderr_with_hz(10));
+            let main_pb = mp.add(
+                ProgressBar::new(entries.len() as u64).with_style(
+                    ProgressStyle::with_template(
+                        "[{elapsed_precise}] {bar:60.cyan/blue} {pos:>7}/{len:7} {msg}",
+                    )
+                    .unwrap(),
+                ),
+            );
+            let pool = Arc::new(WorkerPool::new(&mp, concurrency));
+
+            let total = entries.len() as u64;
+            let client = client.clone();
+            let max_retries = args.max_retries;
+            let errors = run_bulk(
+                entries,
+                concurrency,
+                rate_limiter.clone(),
+                main_pb.clone(),
+                pool,
+                move |entry, spinner| {
+                    let client = client.clone();
+                    async move {
+                        spinner.set_message(format!("loading {}", entry.name));
+                        let mut builder = client
+                            .create_secret()
+                            .name(&entry.name)
+                            .secret_string(&entry.value);
+                        if let Some(description) = &entry.description {
+                            builder = builder.description(description);
+                        }
+                        if !entry.tags.is_empty() {
+                            builder = builder.set_tags(Some(
+                                entry
+                                    .tags
+                                    .iter()
+                                    .map(|(k, v)| Tag::builder().key(k).value(v).build())
+                                    .collect(),
+                            ));
+                        }
+
+                        let result = send_with_retry(max_retries, &entry.name, &spinner, || {
+                            builder.clone().send()
+                        })
+                        .await;
+
+                        match result {
+                            Ok(_) => Ok(Outcome::Done),
+                            Err(e) => match e.try_into_service_error() {
+                                Ok(CreateSecretError::ResourceExistsException(_)) if upsert => {
+                                    spinner.set_message(format!("updating {}", entry.name));
+                                    upsert_secret(max_retries, &client, &spinner, &entry).await?;
+                                    Ok(Outcome::Done)
+                                }
+                                Ok(CreateSecretError::ResourceExistsException(_)) => {
+                                    spinner.println(format!(
+                                        "Secret already exists: {}",
+                                        entry.name
+                                    ));
+                                    Ok(Outcome::Skipped)
+                                }
+                                Ok(err) => Err(err.into()),
+                                Err(other) => Err(other.into()),
+                            },
+                        }
+                    }
+                },
+            )
+            .await?;
+
+            main_pb.finish_with_message(format!(
+                "Loaded {} secrets ({errors} already existed)",
+                total - errors
+            ));
         }
-        Command::Delete { count, prefix } => {
-            let mp = MultiProgress::new();
-            let pb = mp.add(
-                ProgressBar::new(count.get()).with_style(
+        Command::Update {
+            count,
+            prefix,
+            file,
+            tags,
+            untag,
+        } => {
+            let tags_to_set: HashMap<String, String> = tags.into_iter().collect();
+            let targets: Vec<UpdateTarget> = if let Some(file) = file {
+                load_manifest(&file)?
+                    .into_iter()
+                    .map(|entry| UpdateTarget {
+                        name: entry.name,
+                        value: entry.value,
+                        tags_to_set: if entry.tags.is_empty() {
+                            tags_to_set.clone()
+                        } else {
+                            entry.tags
+                        },
+                        tags_to_remove: untag.clone(),
+                    })
+                    .collect()
+            } else {
+                (1..=count.get())
+                    .map(|i| UpdateTarget {
+                        name: format!("{}-{}", prefix, i),
+                        value: format!("rotated-secret-value-{}", i),
+                        tags_to_set: tags_to_set.clone(),
+                        tags_to_remove: untag.clone(),
+                    })
+                    .collect()
+            };
+
+            let mp = MultiProgress::with_draw_target(ProgressDrawTarget::stderr_with_hz(10));
+            let main_pb = mp.add(
+                ProgressBar::new(targets.len() as u64).with_style(
                     ProgressStyle::with_template(
                         "[{elapsed_precise}] {bar:60.cyan/blue} {pos:>7}/{len:7} {msg}",
                     )
                     .unwrap(),
                 ),
             );
+            let pool = Arc::new(WorkerPool::new(&mp, concurrency));
+
+            let total = targets.len() as u64;
+            let client = client.clone();
+            let max_retries = args.max_retries;
+            let errors = run_bulk(
+                targets,
+                concurrency,
+                rate_limiter.clone(),
+                main_pb.clone(),
+                pool,
+                move |target, spinner| {
+                    let client = client.clone();
+                    async move {
+                        spinner.set_message(format!("rotating {}", target.name));
+                        let result = send_with_retry(max_retries, &target.name, &spinner, || {
+                            client
+                                .put_secret_value()
+                                .secret_id(&target.name)
+                                .secret_string(&target.value)
+                                .send()
+                        })
+                        .await;
 
-            for i in pb.wrap_iter(1..=count.get()) {
-                let name = format!("{}-{}", prefix, i);
-
-                match client
-                    .delete_secret()
-                    .secret_id(&name)
-                    .force_delete_without_recovery(true)
-                    .send()
-                    .await
-                {
-                    Ok(_) => pb.set_message(format!("Deleted secret: {}", name)),
-                    Err(e) => match e.into_service_error() {
-                        DeleteSecretError::ResourceNotFoundException(_) => {
-                            pb.println(format!("Secret not found: {}", name));
+                        if let Err(e) = result {
+                            return match e.try_into_service_error() {
+                                Ok(PutSecretValueError::ResourceNotFoundException(_)) => {
+                                    spinner.println(format!("Secret not found: {}", target.name));
+                                    Ok(Outcome::Skipped)
+                                }
+                                Ok(err) => Err(err.into()),
+                                Err(other) => Err(other.into()),
+                            };
+                        }
+
+                        if !target.tags_to_set.is_empty() {
+                            send_with_retry(max_retries, &target.name, &spinner, || {
+                                client
+                                    .tag_resource()
+                                    .secret_id(&target.name)
+                                    .set_tags(Some(
+                                        target
+                                            .tags_to_set
+                                            .iter()
+                                            .map(|(k, v)| Tag::builder().key(k).value(v).build())
+                                            .collect(),
+                                    ))
+                                    .send()
+                            })
+                            .await
+                            .map_err(into_boxed_error)?;
                         }
-                        err => return Err(err.into()),
-                    },
-                }
-            }
 
-            pb.set_message(format!("Deleted {count} secrets"));
-            pb.finish();
+                        if !target.tags_to_remove.is_empty() {
+                            send_with_retry(max_retries, &target.name, &spinner, || {
+                                client
+                                    .untag_resource()
+                                    .secret_id(&target.name)
+                                    .set_tag_keys(Some(target.tags_to_remove.clone()))
+                                    .send()
+                            })
+                            .await
+                            .map_err(into_boxed_error)?;
+                        }
+
+                        Ok(Outcome::Done)
+                    }
+                },
+            )
+            .await?;
+
+            main_pb.finish_with_message(format!(
+                "Rotated {} secrets ({errors} not found)",
+                total - errors
+            ));
         }
     }
 
     Ok(())
 }
 
+/// Pushes a new `AWSCURRENT` value and (if set) description/tags onto an
+/// existing secret. Used by `Load --upsert` when a secret already exists.
+async fn upsert_secret(
+    max_retries: u32,
+    client: &Client,
+    spinner: &ProgressBar,
+    entry: &ManifestEntry,
+) -> Result<(), Box<dyn std::error::Error>> {
+    send_with_retry(max_retries, &entry.name, spinner, || {
+        client
+            .put_secret_value()
+            .secret_id(&entry.name)
+            .secret_string(&entry.value)
+            .send()
+    })
+    .await
+    .map_err(into_boxed_error)?;
+
+    if let Some(description) = &entry.description {
+        send_with_retry(max_retries, &entry.name, spinner, || {
+            client
+                .update_secret()
+                .secret_id(&entry.name)
+                .description(description)
+                .send()
+        })
+        .await
+        .map_err(into_boxed_error)?;
+    }
+
+    if !entry.tags.is_empty() {
+        send_with_retry(max_retries, &entry.name, spinner, || {
+            client
+                .tag_resource()
+                .secret_id(&entry.name)
+                .set_tags(Some(
+                    entry
+                        .tags
+                        .iter()
+                        .map(|(k, v)| Tag::builder().key(k).value(v).build())
+                        .collect(),
+                ))
+                .send()
+        })
+        .await
+        .map_err(into_boxed_error)?;
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use clap::Parser;
@@ -133,4 +825,11 @@ mod tests {
         let result = super::Args::try_parse_from(&["secret-populator", "create", "--count", "0"]);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_concurrency_zero_errors() {
+        let result =
+            super::Args::try_parse_from(&["secret-populator", "--concurrency", "0", "create"]);
+        assert!(result.is_err());
+    }
 }